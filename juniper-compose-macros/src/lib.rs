@@ -6,12 +6,13 @@ use heck::ToLowerCamelCase;
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
 use syn::{
-    parenthesized,
+    bracketed, parenthesized,
     parse::Parse,
     parse2, parse_macro_input,
     punctuated::Punctuated,
     token::{Comma, Paren},
-    Error, Ident, ImplItem, ItemImpl, LitStr, Path, Result, Token, Type,
+    Attribute, Error, Expr, Ident, ImplItem, ImplItemMethod, ItemImpl, LitStr, Path, Result, Token,
+    Type,
 };
 
 #[proc_macro_attribute]
@@ -25,76 +26,275 @@ pub fn composable_object(
 
 #[proc_macro]
 pub fn composite_object(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    let input = parse_macro_input!(input as CompositeObjectInput);
+    let input = parse_macro_input!(input as CompositeInput);
     let context = input
         .context_ty
-        .map_or_else(|| parse2(quote! { () }).unwrap(), |input| input.ty);
-    expand_composite_object(&input.ident, &context, &input.composables).into()
+        .unwrap_or_else(|| parse2(quote! { () }).unwrap());
+    let scalar = input
+        .scalar_ty
+        .unwrap_or_else(|| parse2(quote! { ::juniper::DefaultScalarValue }).unwrap());
+    expand_composite_object(
+        &input.ident,
+        &context,
+        &scalar,
+        &input.interface_tys,
+        &input.composables,
+    )
+    .into()
 }
 
-struct CompositeObjectInput {
+#[proc_macro_attribute]
+pub fn composable_subscription(
+    _: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let item_impl = parse_macro_input!(item as ItemImpl);
+    expand_composable_subscription(&item_impl).into()
+}
+
+#[proc_macro]
+pub fn composite_subscription(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as CompositeInput);
+    if let Some(ty) = input.interface_tys.first() {
+        return Error::new_spanned(ty, "`Interfaces` is not supported on `composite_subscription!`; subscriptions cannot implement GraphQL interfaces")
+            .to_compile_error()
+            .into();
+    }
+    let context = input
+        .context_ty
+        .unwrap_or_else(|| parse2(quote! { () }).unwrap());
+    let scalar = input
+        .scalar_ty
+        .unwrap_or_else(|| parse2(quote! { ::juniper::DefaultScalarValue }).unwrap());
+    expand_composite_subscription(&input.ident, &context, &scalar, &input.composables).into()
+}
+
+#[proc_macro]
+pub fn composite_interface(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as CompositeInput);
+    if let Some(ty) = input.interface_tys.first() {
+        return Error::new_spanned(ty, "`Interfaces` is not supported on `composite_interface!`; GraphQL interfaces cannot implement other interfaces")
+            .to_compile_error()
+            .into();
+    }
+    let context = input
+        .context_ty
+        .unwrap_or_else(|| parse2(quote! { () }).unwrap());
+    let scalar = input
+        .scalar_ty
+        .unwrap_or_else(|| parse2(quote! { ::juniper::DefaultScalarValue }).unwrap());
+    expand_composite_interface(&input.ident, &context, &scalar, &input.composables).into()
+}
+
+struct CompositeInput {
     ident: Ident,
-    context_ty: Option<CompositeObjectCustomContextType>,
+    context_ty: Option<Type>,
+    scalar_ty: Option<Type>,
+    interface_tys: Vec<Type>,
     #[allow(dead_code)]
     paren: Paren,
-    composables: Punctuated<Path, Comma>,
+    composables: Punctuated<Composable, Comma>,
 }
 
-impl Parse for CompositeObjectInput {
+impl Parse for CompositeInput {
     fn parse(input: syn::parse::ParseStream) -> Result<Self> {
         let ident = input.parse()?;
-        let context_ty = if input.peek(Token![<]) {
-            Some(input.parse()?)
-        } else {
-            None
-        };
+        let mut context_ty = None;
+        let mut scalar_ty = None;
+        let mut interface_tys = None;
+        if input.peek(Token![<]) {
+            input.parse::<Token![<]>()?;
+            let params = Punctuated::<CompositeParam, Comma>::parse_separated_nonempty(
+                input,
+            )?;
+            for param in params {
+                match (param.key.to_string().as_str(), param.value) {
+                    ("Context", CompositeParamValue::Ty(ty)) if context_ty.is_none() => {
+                        context_ty = Some(*ty);
+                    }
+                    ("Context", CompositeParamValue::Ty(_)) => {
+                        return Err(Error::new(param.key.span(), "duplicate `Context` parameter"))
+                    }
+                    ("Scalar", CompositeParamValue::Ty(ty)) if scalar_ty.is_none() => {
+                        scalar_ty = Some(*ty);
+                    }
+                    ("Scalar", CompositeParamValue::Ty(_)) => {
+                        return Err(Error::new(param.key.span(), "duplicate `Scalar` parameter"))
+                    }
+                    ("Interfaces", CompositeParamValue::TyList(tys)) if interface_tys.is_none() => {
+                        interface_tys = Some(tys);
+                    }
+                    ("Interfaces", CompositeParamValue::TyList(_)) => {
+                        return Err(Error::new(
+                            param.key.span(),
+                            "duplicate `Interfaces` parameter",
+                        ))
+                    }
+                    _ => {
+                        return Err(Error::new(
+                            param.key.span(),
+                            "expected `Context`, `Scalar` or `Interfaces = [...]`",
+                        ))
+                    }
+                }
+            }
+            input.parse::<Token![>]>()?;
+        }
         let composables;
         let paren = parenthesized!(composables in input);
         Ok(Self {
             ident,
             context_ty,
+            scalar_ty,
+            interface_tys: interface_tys.unwrap_or_default(),
             paren,
-            composables: composables.parse_terminated(Path::parse)?,
+            composables: composables.parse_terminated(Composable::parse)?,
         })
     }
 }
 
-struct CompositeObjectCustomContextType {
-    #[allow(dead_code)]
-    left_angle_bracket: Token![<],
-    #[allow(dead_code)]
-    context_ident: Ident,
+/// A single composable, optionally namespaced with `prefix: Path` syntax. A namespaced
+/// composable's fields are exposed under `prefix`-prefixed names (e.g. `user: UserQueries`
+/// exposes `UserQueries::count` as `userCount`), which lets otherwise-conflicting field names
+/// coexist instead of tripping the duplicate-field check.
+struct Composable {
+    prefix: Option<Ident>,
+    path: Path,
+}
+
+impl Parse for Composable {
+    fn parse(input: syn::parse::ParseStream) -> Result<Self> {
+        // `peek2(Token![:])` alone would also match the first `:` of a `::` path separator
+        // (e.g. `inner::UserQueries`), so a namespace prefix is only recognized when the colon
+        // is not immediately followed by another one.
+        let prefix = if input.peek(Ident) && input.peek2(Token![:]) && !input.peek2(Token![::]) {
+            let prefix = input.parse()?;
+            input.parse::<Token![:]>()?;
+            Some(prefix)
+        } else {
+            None
+        };
+        Ok(Self {
+            prefix,
+            path: input.parse()?,
+        })
+    }
+}
+
+/// The value of a single `Key = ...` entry: either a bare type, as in `Context = MyContext`,
+/// or a bracketed list of types, as in `Interfaces = [Node, Timestamped]`.
+enum CompositeParamValue {
+    Ty(Box<Type>),
+    TyList(Vec<Type>),
+}
+
+/// A single `Key = Type` or `Key = [Type, ...]` entry inside the `<...>` parameter list, e.g.
+/// `Context = MyContext`, `Scalar = MyScalarValue` or `Interfaces = [Node, Timestamped]`.
+struct CompositeParam {
+    key: Ident,
     #[allow(dead_code)]
     eq_token: Token![=],
-    ty: Type,
-    #[allow(dead_code)]
-    right_angle_bracket: Token![>],
+    value: CompositeParamValue,
 }
 
-impl Parse for CompositeObjectCustomContextType {
+impl Parse for CompositeParam {
     fn parse(input: syn::parse::ParseStream) -> Result<Self> {
-        let left_angle_bracket = input.parse()?;
-        let context_ident = input.parse::<Ident>()?;
-        if context_ident != "Context" {
-            return Err(Error::new(context_ident.span(), "expected `Context`"));
-        }
+        let key: Ident = input.parse()?;
         let eq_token = input.parse()?;
-        let ty = input.parse()?;
-        let right_angle_bracket = input.parse()?;
+        let value = if input.peek(syn::token::Bracket) {
+            let tys;
+            bracketed!(tys in input);
+            let tys = Punctuated::<Type, Comma>::parse_terminated(&tys)?;
+            CompositeParamValue::TyList(tys.into_iter().collect())
+        } else {
+            CompositeParamValue::Ty(Box::new(input.parse()?))
+        };
         Ok(Self {
-            left_angle_bracket,
-            context_ident,
+            key,
             eq_token,
-            ty,
-            right_angle_bracket,
+            value,
         })
     }
 }
 
+/// A single item inside a `#[graphql(...)]` field attribute: `name = "..."` or `skip`, which we
+/// act on, or any other key (e.g. `description = "..."`, `deprecated = "..."`, or a nested,
+/// parenthesized shape like `arguments(id(description = "..."))`), which is juniper's own
+/// business — we only need to recognize enough of its shape to skip over it without misparsing
+/// the next item.
+enum FieldAttrItem {
+    Name(LitStr),
+    Skip,
+    Other,
+}
+
+impl Parse for FieldAttrItem {
+    fn parse(input: syn::parse::ParseStream) -> Result<Self> {
+        let ident: Ident = input.parse()?;
+        if ident == "name" {
+            input.parse::<Token![=]>()?;
+            Ok(Self::Name(input.parse()?))
+        } else if ident == "skip" {
+            Ok(Self::Skip)
+        } else {
+            if input.peek(Token![=]) {
+                input.parse::<Token![=]>()?;
+                input.parse::<Expr>()?;
+            } else if input.peek(Paren) {
+                let group;
+                parenthesized!(group in input);
+                group.parse::<TokenStream>()?;
+            }
+            Ok(Self::Other)
+        }
+    }
+}
+
+/// The effective `#[graphql(...)]` configuration of a composed method, merged across every
+/// `#[graphql(...)]` attribute attached to it.
+struct FieldAttrs {
+    name: Option<LitStr>,
+    skip: bool,
+}
+
+fn parse_field_attrs(attrs: &[Attribute]) -> Result<FieldAttrs> {
+    let mut name = None;
+    let mut skip = false;
+    for attr in attrs {
+        if !attr.path.is_ident("graphql") {
+            continue;
+        }
+        let items = attr.parse_args_with(Punctuated::<FieldAttrItem, Comma>::parse_terminated)?;
+        for item in items {
+            match item {
+                FieldAttrItem::Name(lit) => name = Some(lit),
+                FieldAttrItem::Skip => skip = true,
+                FieldAttrItem::Other => {}
+            }
+        }
+    }
+    Ok(FieldAttrs { name, skip })
+}
+
+/// The GraphQL field name a composed method is exposed as, after `#[graphql(name = "...")]`
+/// renames and `#[graphql(skip)]` exclusions are applied. `None` if the method is skipped.
+fn field_name(method: &ImplItemMethod) -> Result<Option<LitStr>> {
+    let attrs = parse_field_attrs(&method.attrs)?;
+    if attrs.skip {
+        return Ok(None);
+    }
+    Ok(Some(attrs.name.unwrap_or_else(|| {
+        LitStr::new(
+            &method.sig.ident.to_string().to_lower_camel_case(),
+            Span::call_site(),
+        )
+    })))
+}
+
 fn expand_composable_object(item_impl: &ItemImpl) -> TokenStream {
     let ty = &item_impl.self_ty;
 
-    let fields = item_impl
+    let fields = match item_impl
         .items
         .iter()
         .filter_map(|item| {
@@ -104,104 +304,416 @@ fn expand_composable_object(item_impl: &ItemImpl) -> TokenStream {
                 None
             }
         })
-        .map(|method| {
-            LitStr::new(
-                &method.sig.ident.to_string().to_lower_camel_case(),
-                Span::call_site(),
-            )
-        });
+        .filter_map(|method| field_name(method).transpose())
+        .collect::<Result<Vec<_>>>()
+    {
+        Ok(fields) => fields,
+        Err(err) => {
+            let err = err.to_compile_error();
+            // Re-emit `item_impl` even on a field-attribute parse failure, so the user gets
+            // one focused diagnostic instead of losing the type entirely and facing a wall of
+            // unrelated "trait bound not satisfied" errors on every other use of it.
+            return quote! {
+                #err
+                #item_impl
+            };
+        }
+    };
 
     quote! {
-        impl ::juniper_compose::ComposableObject for #ty {
-            fn fields() -> &'static [&'static str] {
-                &[#( #fields ),*]
+        impl<S> ::juniper_compose::ComposableObject<S> for #ty
+        where
+            S: ::juniper::ScalarValue + ::std::marker::Send + ::std::marker::Sync,
+            Self: ::juniper::GraphQLTypeAsync<S>,
+            <Self as ::juniper::GraphQLValue<S>>::Context: ::std::marker::Sync,
+            <Self as ::juniper::GraphQLValue<S>>::TypeInfo: ::std::marker::Sync,
+        {
+            const FIELDS: &'static [&'static str] = &[#( #fields ),*];
+        }
+
+        #item_impl
+    }
+}
+
+fn expand_composable_subscription(item_impl: &ItemImpl) -> TokenStream {
+    let ty = &item_impl.self_ty;
+
+    let fields = match item_impl
+        .items
+        .iter()
+        .filter_map(|item| {
+            if let ImplItem::Method(method) = item {
+                Some(method)
+            } else {
+                None
             }
+        })
+        .filter_map(|method| field_name(method).transpose())
+        .collect::<Result<Vec<_>>>()
+    {
+        Ok(fields) => fields,
+        Err(err) => {
+            let err = err.to_compile_error();
+            // Re-emit `item_impl` even on a field-attribute parse failure, so the user gets
+            // one focused diagnostic instead of losing the type entirely and facing a wall of
+            // unrelated "trait bound not satisfied" errors on every other use of it.
+            return quote! {
+                #err
+                #item_impl
+            };
+        }
+    };
+
+    quote! {
+        impl<S> ::juniper_compose::ComposableSubscription<S> for #ty
+        where
+            S: ::juniper::ScalarValue + ::std::marker::Send + ::std::marker::Sync,
+            Self: ::juniper::GraphQLSubscriptionType<S>,
+            <Self as ::juniper::GraphQLValue<S>>::Context: ::std::marker::Sync,
+            <Self as ::juniper::GraphQLValue<S>>::TypeInfo: ::std::marker::Sync,
+        {
+            const FIELDS: &'static [&'static str] = &[#( #fields ),*];
         }
 
         #item_impl
     }
 }
 
+fn expand_composite_subscription<P>(
+    name: &Ident,
+    context: &Type,
+    scalar: &Type,
+    composables: &Punctuated<Composable, P>,
+) -> TokenStream {
+    let name_lit = LitStr::new(&name.to_string(), Span::call_site());
+    let kind = CompositeKind {
+        composable_trait: &quote! { ::juniper_compose::ComposableSubscription },
+        build_method: &quote! { build_object_type },
+        conflict_description: "composed subscriptions",
+    };
+    let impl_graphql_type =
+        expand_impl_graphql_type(name, &name_lit, scalar, &kind, &[], composables.iter());
+    let impl_graphql_value = expand_impl_graphql_value_for_subscription(name, &name_lit, context, scalar);
+    let impl_graphql_subscription_value =
+        expand_impl_graphql_subscription_value(name, &name_lit, scalar, composables.iter());
+    quote! {
+        #[derive(::std::default::Default)]
+        struct #name;
+        #impl_graphql_type
+        #impl_graphql_value
+        #impl_graphql_subscription_value
+    }
+}
+
+fn expand_impl_graphql_value_for_subscription(
+    name: &Ident,
+    name_lit: &LitStr,
+    context: &Type,
+    scalar: &Type,
+) -> TokenStream {
+    quote! {
+        impl ::juniper::GraphQLValue<#scalar> for #name {
+            type Context = #context;
+            type TypeInfo = ();
+
+            fn type_name<'i>(&self, info: &'i Self::TypeInfo) -> Option<&'i str> {
+                <Self as ::juniper::GraphQLType<#scalar>>::name(info)
+            }
+
+            fn concrete_type_name(
+                &self,
+                context: &Self::Context,
+                info: &Self::TypeInfo
+            ) -> String {
+                String::from(#name_lit)
+            }
+        }
+    }
+}
+
+/// Builds the `if ... { <tail> }` guard that routes an incoming field name to `composable`.
+/// For a prefixed composable, the incoming field name is first stripped of its namespace and
+/// the resulting (owned) field name is what gets forwarded; for a bare composable, the
+/// incoming field name is forwarded as-is. `tail` receives the token expression for the field
+/// name to forward, already coerced to `&str`, and produces the body to run once the guard
+/// passes.
+fn dispatch_guard(
+    composable: &Composable,
+    scalar: &Type,
+    composable_trait: &TokenStream,
+    tail: impl FnOnce(TokenStream) -> TokenStream,
+) -> TokenStream {
+    let path = &composable.path;
+    if let Some(prefix) = &composable.prefix {
+        let prefix_lit = LitStr::new(&prefix.to_string(), prefix.span());
+        let body = tail(quote! { inner_field_name.as_str() });
+        quote! {
+            if let ::std::option::Option::Some(inner_field_name) = ::juniper_compose::strip_field_prefix(#prefix_lit, field_name) {
+                if <#path as #composable_trait<#scalar>>::FIELDS.contains(&inner_field_name.as_str()) {
+                    #body
+                }
+            }
+        }
+    } else {
+        let body = tail(quote! { field_name });
+        quote! {
+            if <#path as #composable_trait<#scalar>>::FIELDS.contains(&field_name) {
+                #body
+            }
+        }
+    }
+}
+
+fn expand_impl_graphql_subscription_value<'a>(
+    name: &Ident,
+    name_lit: &LitStr,
+    scalar: &Type,
+    composables: impl IntoIterator<Item = &'a Composable>,
+) -> TokenStream {
+    let composable_trait = quote! { ::juniper_compose::ComposableSubscription };
+    let dispatch_blocks = composables.into_iter().map(|composable| {
+        let path = &composable.path;
+        dispatch_guard(composable, scalar, &composable_trait, |field_name| {
+            quote! {
+                return ::std::boxed::Box::pin(async move {
+                    <#path as ::juniper::GraphQLSubscriptionValue<#scalar>>::resolve_field_into_stream(
+                        &<#path as ::std::default::Default>::default(),
+                        info,
+                        #field_name,
+                        args,
+                        executor
+                    ).await
+                });
+            }
+        })
+    });
+    quote! {
+        impl ::juniper::GraphQLSubscriptionValue<#scalar> for #name
+        where
+            Self::TypeInfo: Sync,
+            Self::Context: Sync,
+        {
+            fn resolve_field_into_stream<'s, 'i, 'ft, 'args, 'e, 'ref_e, 'res, 'f>(
+                &'s self,
+                info: &'i Self::TypeInfo,
+                field_name: &'ft str,
+                args: ::juniper::Arguments<'args, #scalar>,
+                executor: &'ref_e ::juniper::Executor<'ref_e, 'e, Self::Context, #scalar>,
+            ) -> ::juniper::BoxFuture<'f, ::std::result::Result<::juniper::Value<::juniper::ValuesStream<'res, #scalar>>, ::juniper::FieldError<#scalar>>>
+            where
+                's: 'f,
+                'i: 'res,
+                'ft: 'f,
+                'args: 'f,
+                'ref_e: 'f,
+                'res: 'f,
+                'e: 'res,
+            {
+                #( #dispatch_blocks )*
+                ::std::boxed::Box::pin(async move { Err(::juniper::FieldError::from(::std::format!(
+                    "Field `{}` not found on type `{}`",
+                    field_name,
+                    #name_lit,
+                ))) })
+            }
+        }
+    }
+}
+
+/// Composes a GraphQL interface out of existing [`ComposableObject`](::juniper_compose::ComposableObject)
+/// field groups, the same ones used by `composite_object!`, so a field set can be shared between
+/// an interface and the object types that implement it.
+fn expand_composite_interface<P>(
+    name: &Ident,
+    context: &Type,
+    scalar: &Type,
+    composables: &Punctuated<Composable, P>,
+) -> TokenStream {
+    let name_lit = LitStr::new(&name.to_string(), Span::call_site());
+    let kind = CompositeKind {
+        composable_trait: &quote! { ::juniper_compose::ComposableObject },
+        build_method: &quote! { build_interface_type },
+        conflict_description: "composed interface",
+    };
+    let impl_graphql_type =
+        expand_impl_graphql_type(name, &name_lit, scalar, &kind, &[], composables.iter());
+    let impl_graphql_value =
+        expand_impl_graphql_value(name, &name_lit, context, scalar, composables.iter());
+    let impl_graphql_value_async =
+        expand_impl_graphql_value_async(name, &name_lit, scalar, composables.iter());
+    quote! {
+        #[derive(::std::default::Default)]
+        struct #name;
+        impl ::juniper::marker::IsOutputType<#scalar> for #name {}
+        #impl_graphql_type
+        #impl_graphql_value
+        #impl_graphql_value_async
+    }
+}
+
 fn expand_composite_object<P>(
     name: &Ident,
     context: &Type,
-    composables: &Punctuated<Path, P>,
+    scalar: &Type,
+    interfaces: &[Type],
+    composables: &Punctuated<Composable, P>,
 ) -> TokenStream {
     let name_lit = LitStr::new(&name.to_string(), Span::call_site());
-    let impl_graphql_type = expand_impl_graphql_type(name, &name_lit, composables.iter());
+    let kind = CompositeKind {
+        composable_trait: &quote! { ::juniper_compose::ComposableObject },
+        build_method: &quote! { build_object_type },
+        conflict_description: "composed objects",
+    };
+    let impl_graphql_type = expand_impl_graphql_type(
+        name,
+        &name_lit,
+        scalar,
+        &kind,
+        interfaces,
+        composables.iter(),
+    );
     let impl_graphql_value =
-        expand_impl_graphql_value(name, &name_lit, context, composables.iter());
+        expand_impl_graphql_value(name, &name_lit, context, scalar, composables.iter());
     let impl_graphql_value_async =
-        expand_impl_graphql_value_async(name, &name_lit, composables.iter());
+        expand_impl_graphql_value_async(name, &name_lit, scalar, composables.iter());
     quote! {
         #[derive(::std::default::Default)]
         struct #name;
+        impl ::juniper::marker::IsOutputType<#scalar> for #name {}
         #impl_graphql_type
         #impl_graphql_value
         #impl_graphql_value_async
     }
 }
 
+/// The parts of [expand_impl_graphql_type](expand_impl_graphql_type) that are fixed per calling
+/// macro: which `Composable*` trait carries each composable's `FIELDS`, which `Registry` builder
+/// (`build_object_type`/`build_interface_type`) assembles the `MetaType`, and how to describe a
+/// field conflict in the generated `assert!` message.
+struct CompositeKind<'a> {
+    composable_trait: &'a TokenStream,
+    build_method: &'a TokenStream,
+    conflict_description: &'a str,
+}
+
+/// Generates the `GraphQLType` impl for a composite type, merging `meta()` across every
+/// composable. Shared by `composite_object!`, `composite_subscription!` and `composite_interface!`,
+/// which differ only in `kind`.
+///
+/// `interfaces` lists the GraphQL interfaces the composed type declares itself to implement
+/// (only meaningful for `composite_object!`); each is registered on the emitted `ObjectMeta` so
+/// that the interface's fields can be queried polymorphically through it.
 fn expand_impl_graphql_type<'a>(
     name: &Ident,
     name_lit: &LitStr,
-    composables: impl IntoIterator<Item = &'a Path>,
+    scalar: &Type,
+    kind: &CompositeKind<'_>,
+    interfaces: &[Type],
+    composables: impl IntoIterator<Item = &'a Composable>,
 ) -> TokenStream {
-    let composables = composables.into_iter();
+    let CompositeKind {
+        composable_trait,
+        build_method,
+        conflict_description,
+    } = kind;
+    let composables: Vec<_> = composables.into_iter().collect();
+
+    // Paired with each composable's own prefix (`None` for an unprefixed composable) so
+    // `no_duplicate_fields` can check fully-expanded field names against each other, not just
+    // each composable's raw, unprefixed ones.
+    let composable_field_lists = composables.iter().map(|composable| {
+        let path = &composable.path;
+        let prefix_expr = if let Some(prefix) = &composable.prefix {
+            let prefix_lit = LitStr::new(&prefix.to_string(), prefix.span());
+            quote! { ::std::option::Option::Some(#prefix_lit) }
+        } else {
+            quote! { ::std::option::Option::None }
+        };
+        quote! { (#prefix_expr, <#path as #composable_trait<#scalar>>::FIELDS) }
+    });
+
+    let meta_blocks = composables.iter().map(|composable| {
+        let path = &composable.path;
+        let name_expr = if let Some(prefix) = &composable.prefix {
+            let prefix_lit = LitStr::new(&prefix.to_string(), prefix.span());
+            quote! { ::std::convert::Into::into(::juniper_compose::prefixed_field_name(#prefix_lit, field_name)) }
+        } else {
+            quote! { composable_field.name.clone() }
+        };
+        quote! {
+            let composable_meta = <#path as ::juniper::GraphQLType<#scalar>>::meta(info, registry);
+
+            for field_name in <#path as #composable_trait<#scalar>>::FIELDS {
+                let composable_field = composable_meta
+                    .field_by_name(field_name)
+                    .unwrap_or_else(|| {
+                        ::std::panic!(
+                            "Incorrect implementation of {} on type {}: unknown field {}",
+                            ::std::stringify!(#composable_trait),
+                            <#path as ::juniper::GraphQLType<#scalar>>::name(&()).unwrap_or("<anonymous>"), field_name
+                        )
+                    });
+
+                fields.push(::juniper::meta::Field {
+                    name: #name_expr,
+                    description: composable_field.description.clone(),
+                    arguments: composable_field.arguments.as_ref().map(|arguments| {
+                        arguments
+                            .iter()
+                            .map(|argument| ::juniper::meta::Argument {
+                                name: argument.name.clone(),
+                                description: argument.description.clone(),
+                                arg_type: ::juniper_compose::type_to_owned(&argument.arg_type),
+                                default_value: argument.default_value.clone(),
+                            })
+                            .collect()
+                    }),
+                    field_type: ::juniper_compose::type_to_owned(&composable_field.field_type),
+                    deprecation_status: composable_field.deprecation_status.clone(),
+                });
+            }
+        }
+    });
+
+    let interface_type_exprs = interfaces
+        .iter()
+        .map(|interface| quote! { registry.get_type::<#interface>(&()) });
+    let build_expr = if interfaces.is_empty() {
+        quote! { registry.#build_method::<Self>(&(), &fields).into_meta() }
+    } else {
+        quote! {
+            registry
+                .#build_method::<Self>(&(), &fields)
+                .interfaces(&[#( #interface_type_exprs ),*])
+                .into_meta()
+        }
+    };
+
     quote! {
-        impl ::juniper::GraphQLType for #name {
+        const _: () = ::std::assert!(
+            ::juniper_compose::no_duplicate_fields(
+                &[#( #composable_field_lists ),*]
+            ),
+            ::std::concat!("Conflicting field in ", #conflict_description)
+        );
+
+        impl ::juniper::GraphQLType<#scalar> for #name {
             fn name(info: &Self::TypeInfo) -> ::std::option::Option<&str> {
                 ::std::option::Option::Some(#name_lit)
             }
 
             fn meta<'r>(
                 info: &Self::TypeInfo,
-                registry: &mut ::juniper::executor::Registry<'r, ::juniper::DefaultScalarValue>
-            ) -> ::juniper::meta::MetaType<'r, ::juniper::DefaultScalarValue>
+                registry: &mut ::juniper::executor::Registry<'r, #scalar>
+            ) -> ::juniper::meta::MetaType<'r, #scalar>
             where
-                ::juniper::DefaultScalarValue: 'r
+                #scalar: 'r
             {
                 let mut fields = ::std::vec![];
-                let mut seen_field_names = ::std::collections::HashSet::<&str>::new();
-
-                #(
-                    let composable_meta = <#composables as ::juniper::GraphQLType>::meta(info, registry);
-
-                    for field_name in <#composables as ::juniper_compose::ComposableObject>::fields() {
-                        if !seen_field_names.insert(field_name) {
-                            ::std::panic!("Conflicting field in composed objects: {}", field_name);
-                        }
-
-                        let composable_field = composable_meta
-                            .field_by_name(field_name)
-                            .unwrap_or_else(|| {
-                                ::std::panic!(
-                                    "Incorrect implementation of ComposableObject on type {}: unknown field {}",
-                                    <#composables as ::juniper::GraphQLType>::name(&()).unwrap_or("<anonymous>"), field_name
-                                )
-                            });
-
-                        fields.push(::juniper::meta::Field {
-                            name: composable_field.name.clone(),
-                            description: composable_field.description.clone(),
-                            arguments: composable_field.arguments.as_ref().map(|arguments| {
-                                arguments
-                                    .iter()
-                                    .map(|argument| ::juniper::meta::Argument {
-                                        name: argument.name.clone(),
-                                        description: argument.description.clone(),
-                                        arg_type: ::juniper_compose::type_to_owned(&argument.arg_type),
-                                        default_value: argument.default_value.clone(),
-                                    })
-                                    .collect()
-                            }),
-                            field_type: ::juniper_compose::type_to_owned(&composable_field.field_type),
-                            deprecation_status: composable_field.deprecation_status.clone(),
-                        });
-                    }
-                )*
 
-                registry.build_object_type::<Self>(&(), &fields).into_meta()
+                #( #meta_blocks )*
+
+                #build_expr
             }
         }
     }
@@ -211,36 +723,41 @@ fn expand_impl_graphql_value<'a>(
     name: &Ident,
     name_lit: &LitStr,
     context: &Type,
-    composables: impl IntoIterator<Item = &'a Path>,
+    scalar: &Type,
+    composables: impl IntoIterator<Item = &'a Composable>,
 ) -> TokenStream {
-    let composables = composables.into_iter();
+    let composable_trait = quote! { ::juniper_compose::ComposableObject };
+    let dispatch_blocks = composables.into_iter().map(|composable| {
+        let path = &composable.path;
+        dispatch_guard(composable, scalar, &composable_trait, |field_name| {
+            quote! {
+                return <#path as ::juniper::GraphQLValue<#scalar>>::resolve_field(
+                    &<#path as ::std::default::Default>::default(),
+                    info,
+                    #field_name,
+                    arguments,
+                    executor
+                );
+            }
+        })
+    });
     quote! {
-        impl ::juniper::GraphQLValue for #name {
+        impl ::juniper::GraphQLValue<#scalar> for #name {
             type Context = #context;
             type TypeInfo = ();
 
             fn type_name<'i>(&self, info: &'i Self::TypeInfo) -> Option<&'i str> {
-                <Self as ::juniper::GraphQLType>::name(info)
+                <Self as ::juniper::GraphQLType<#scalar>>::name(info)
             }
 
             fn resolve_field(
                 &self,
                 info: &Self::TypeInfo,
                 field_name: &str,
-                arguments: &::juniper::Arguments<'_, ::juniper::DefaultScalarValue>,
-                executor: &::juniper::executor::Executor<'_, '_, Self::Context, ::juniper::DefaultScalarValue>
-            ) -> ::juniper::executor::ExecutionResult<::juniper::DefaultScalarValue> {
-                #(
-                    if <#composables as ::juniper_compose::ComposableObject>::fields().contains(&field_name) {
-                        return <#composables as ::juniper::GraphQLValue>::resolve_field(
-                            &<#composables as ::std::default::Default>::default(),
-                            info,
-                            field_name,
-                            arguments,
-                            executor
-                        );
-                    }
-                )*
+                arguments: &::juniper::Arguments<'_, #scalar>,
+                executor: &::juniper::executor::Executor<'_, '_, Self::Context, #scalar>
+            ) -> ::juniper::executor::ExecutionResult<#scalar> {
+                #( #dispatch_blocks )*
                 Err(::juniper::FieldError::from(::std::format!(
                     "Field `{}` not found on type `{}`",
                     field_name,
@@ -262,11 +779,28 @@ fn expand_impl_graphql_value<'a>(
 fn expand_impl_graphql_value_async<'a>(
     name: &Ident,
     name_lit: &LitStr,
-    composables: impl IntoIterator<Item = &'a Path>,
+    scalar: &Type,
+    composables: impl IntoIterator<Item = &'a Composable>,
 ) -> TokenStream {
-    let composables = composables.into_iter();
+    let composable_trait = quote! { ::juniper_compose::ComposableObject };
+    let dispatch_blocks = composables.into_iter().map(|composable| {
+        let path = &composable.path;
+        dispatch_guard(composable, scalar, &composable_trait, |field_name| {
+            quote! {
+                return ::std::boxed::Box::pin(async move {
+                    <#path as ::juniper::GraphQLValueAsync<#scalar>>::resolve_field_async(
+                        &<#path as ::std::default::Default>::default(),
+                        info,
+                        #field_name,
+                        arguments,
+                        executor
+                    ).await
+                })
+            }
+        })
+    });
     quote! {
-        impl ::juniper::GraphQLValueAsync for #name
+        impl ::juniper::GraphQLValueAsync<#scalar> for #name
         where
             Self::TypeInfo: Sync,
             Self::Context: Sync,
@@ -275,22 +809,10 @@ fn expand_impl_graphql_value_async<'a>(
                 &'a self,
                 info: &'a Self::TypeInfo,
                 field_name: &'a str,
-                arguments: &'a ::juniper::Arguments<'_, ::juniper::DefaultScalarValue>,
-                executor: &'a ::juniper::executor::Executor<'_, '_, Self::Context, ::juniper::DefaultScalarValue>
-            ) -> ::juniper::BoxFuture<'a, ::juniper::executor::ExecutionResult<::juniper::DefaultScalarValue>> {
-                #(
-                    if <#composables as ::juniper_compose::ComposableObject>::fields().contains(&field_name) {
-                        return ::std::boxed::Box::pin(async move {
-                            <#composables as ::juniper::GraphQLValueAsync>::resolve_field_async(
-                                &<#composables as ::std::default::Default>::default(),
-                                info,
-                                field_name,
-                                arguments,
-                                executor
-                            ).await
-                        })
-                    }
-                )*
+                arguments: &'a ::juniper::Arguments<'_, #scalar>,
+                executor: &'a ::juniper::executor::Executor<'_, '_, Self::Context, #scalar>
+            ) -> ::juniper::BoxFuture<'a, ::juniper::executor::ExecutionResult<#scalar>> {
+                #( #dispatch_blocks )*
                 ::std::boxed::Box::pin(async move { Err(::juniper::FieldError::from(::std::format!(
                     "Field `{}` not found on type `{}`",
                     field_name,
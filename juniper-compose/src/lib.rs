@@ -82,9 +82,95 @@
 //! composite_object!(Query<Context = MyCustomContext>(UserQueries, TaskQueries));
 //! ```
 //!
-//! Custom scalars are currently not supported, but will be added if requested.
+//! Custom scalars are supported too, and can be combined with a custom context:
+//!
+//! ```
+//! composite_object!(Query<Scalar = MyCustomScalar>(UserQueries, TaskQueries));
+//! composite_object!(Query<Context = MyCustomContext, Scalar = MyCustomScalar>(UserQueries, TaskQueries));
+//! ```
+//!
+//! If two composables would otherwise expose a field with the same name, disambiguate them
+//! with `#[graphql(name = "...")]`, or drop a helper method from the composed schema entirely
+//! with `#[graphql(skip)]`:
+//!
+//! ```
+//! #[composable_object]
+//! #[juniper::graphql_object]
+//! impl UserQueries {
+//!     #[graphql(name = "userCount")]
+//!     async fn count(ctx: &Context) -> i32 {
+//!         // ...
+//!     }
+//!
+//!     #[graphql(skip)]
+//!     async fn helper(&self) -> i32 {
+//!         // ...
+//!     }
+//! }
+//! ```
+//!
+//! Leaving two composables' conflicting fields unrenamed is a compile-time error, not a runtime
+//! panic:
+//!
+//! ```compile_fail
+//! # use juniper_compose::{composable_object, composite_object};
+//! # struct Context;
+//! # impl juniper::Context for Context {}
+//! #[derive(Default)]
+//! struct UserQueries;
+//!
+//! #[composable_object]
+//! #[juniper::graphql_object(Context = Context)]
+//! impl UserQueries {
+//!     fn count(&self) -> i32 { 1 }
+//! }
+//!
+//! #[derive(Default)]
+//! struct TaskQueries;
+//!
+//! #[composable_object]
+//! #[juniper::graphql_object(Context = Context)]
+//! impl TaskQueries {
+//!     fn count(&self) -> i32 { 2 }
+//! }
+//!
+//! composite_object!(Query<Context = Context>(UserQueries, TaskQueries));
+//! ```
+//!
+//! Subscription resolvers can be split up and composed the same way, using
+//! `composable_subscription` and `composite_subscription!`:
+//!
+//! ```
+//! #[composable_subscription]
+//! #[juniper::graphql_subscription]
+//! impl UserSubscriptions {
+//!     // ...
+//! }
+//!
+//! composite_subscription!(Subscription(UserSubscriptions, TaskSubscriptions));
+//! ```
+//!
+//! [ComposableObject](ComposableObject) field groups can also be attached to a GraphQL
+//! interface with `composite_interface!`, so a field set shared between an interface and its
+//! implementors only needs to be defined once. Object types then opt into the interface with
+//! `Interfaces = [...]`:
+//!
+//! ```
+//! composite_interface!(Node(IdentifiableFields, TimestampedFields));
+//! composite_object!(User<Interfaces = [Node]>(UserFields));
+//! ```
+//!
+//! If renaming conflicting fields individually is inconvenient, a composable can instead be
+//! given a namespace prefix, which is applied to every one of its fields:
+//!
+//! ```
+//! composite_object!(Query(user: UserQueries, task: TaskQueries));
+//! ```
+//!
+//! This exposes `UserQueries::count` and `TaskQueries::count` as `userCount` and `taskCount`
+//! respectively, without either composable needing to know about the other.
 
-use juniper::{GraphQLTypeAsync, Type};
+use juniper::{GraphQLSubscriptionType, GraphQLTypeAsync, ScalarValue, Type};
 use std::borrow::Cow;
 
 /// Implements [ComposableObject](ComposableObject) for a GraphQL object type.
@@ -102,30 +188,105 @@ use std::borrow::Cow;
 pub use juniper_compose_macros::composable_object;
 
 /// Composes an object type from multiple [ComposableObject](ComposableObject)s.
-/// Custom context type may be specified, otherwise defaults to `()`.
+/// Custom context type may be specified, otherwise defaults to `()`. Custom scalar type
+/// may be specified, otherwise defaults to [`DefaultScalarValue`](juniper::DefaultScalarValue).
+///
+/// A composable may be prefixed with a `name:` namespace, which is applied to every one of
+/// its fields instead of rejecting them as conflicts.
+///
+/// To make the composed object implement one or more GraphQL interfaces built with
+/// [composite_interface](composite_interface), list them with `Interfaces = [...]`. This
+/// registers each interface on the emitted object type, so introspection lists it among the
+/// object's `interfaces` and the object among the interface's `possibleTypes`. A field typed as
+/// a composed interface resolves the interface's own fields through its own composables; it
+/// does not resolve to an implementing object, so an inline fragment on an implementing type
+/// does not match there.
+/// A composed object can also be returned from a field on another, non-composed
+/// `#[graphql_object]` type, as long as that type pins the same `Scalar` (the composed object's
+/// own default, [`DefaultScalarValue`](juniper::DefaultScalarValue), unless overridden) instead
+/// of staying generic over it.
 ///
 /// ## Examples
 ///
 /// ```
 /// composite_object!(Query(UserQueries, TaskQueries));
 /// composite_object!(Mutation<Context = MyContextType>(UserMutations, TaskMutations));
+/// composite_object!(Query<Scalar = MyScalarValue>(UserQueries, TaskQueries));
+/// composite_object!(Query(user: UserQueries, task: TaskQueries));
+/// composite_object!(User<Interfaces = [Node, Timestamped]>(UserFields));
 /// ```
 pub use juniper_compose_macros::composite_object;
 
 /// Object types that you want to compose into one must implement this trait.
 /// Use [composable_object](composable_object) to implement it.
-pub trait ComposableObject: GraphQLTypeAsync + Default
+pub trait ComposableObject<S = juniper::DefaultScalarValue>: GraphQLTypeAsync<S> + Default
+where
+    S: ScalarValue + Send + Sync,
+    Self::Context: Sync,
+    Self::TypeInfo: Sync,
+{
+    /// The fields that exist on this object type.
+    const FIELDS: &'static [&'static str];
+}
+
+/// Implements [ComposableSubscription](ComposableSubscription) for a GraphQL subscription type.
+/// **Important**: must be applied before the `juniper::graphql_subscription` macro.
+///
+/// ## Example
+///
+/// ```
+/// #[composable_subscription]
+/// #[graphql_subscription]
+/// impl UserSubscriptions {
+///     // ...
+/// }
+/// ```
+pub use juniper_compose_macros::composable_subscription;
+
+/// Composes a subscription type from multiple [ComposableSubscription](ComposableSubscription)s.
+/// Custom context and scalar types may be specified the same way as for
+/// [composite_object](composite_object).
+///
+/// ## Examples
+///
+/// ```
+/// composite_subscription!(Subscription(UserSubscriptions, TaskSubscriptions));
+/// composite_subscription!(Subscription<Context = MyContextType>(UserSubscriptions, TaskSubscriptions));
+/// ```
+pub use juniper_compose_macros::composite_subscription;
+
+/// Subscription types that you want to compose into one must implement this trait.
+/// Use [composable_subscription](composable_subscription) to implement it.
+pub trait ComposableSubscription<S = juniper::DefaultScalarValue>:
+    GraphQLSubscriptionType<S> + Default
 where
+    S: ScalarValue + Send + Sync,
     Self::Context: Sync,
     Self::TypeInfo: Sync,
 {
-    /// Returns a list of fields that exist on this object type.
-    fn fields() -> &'static [&'static str];
+    /// The fields that exist on this subscription type.
+    const FIELDS: &'static [&'static str];
 }
 
+/// Composes a GraphQL interface from multiple [ComposableObject](ComposableObject) field
+/// groups, the same ones used by [composite_object](composite_object). Custom context and
+/// scalar types may be specified the same way as for [composite_object](composite_object).
+///
+/// An interface on its own only declares a shared field set; to attach it to the object types
+/// that implement it, list it in their `composite_object!` invocation with `Interfaces = [...]`.
+///
+/// ## Examples
+///
+/// ```
+/// composite_interface!(Node(IdentifiableFields, TimestampedFields));
+/// composite_interface!(Node<Context = MyContextType>(IdentifiableFields, TimestampedFields));
+/// composite_object!(User<Interfaces = [Node]>(UserFields));
+/// ```
+pub use juniper_compose_macros::composite_interface;
+
 #[doc(hidden)]
 #[allow(clippy::must_use_candidate)]
-pub fn type_to_owned<'a>(ty: &Type<'a>) -> Type<'static> {
+pub fn type_to_owned(ty: &Type<'_>) -> Type<'static> {
     match ty {
         Type::Named(name) => Type::Named(Cow::Owned(name.to_string())),
         Type::NonNullNamed(name) => Type::NonNullNamed(Cow::Owned(name.to_string())),
@@ -133,3 +294,162 @@ pub fn type_to_owned<'a>(ty: &Type<'a>) -> Type<'static> {
         Type::NonNullList(inner) => Type::NonNullList(Box::new(type_to_owned(inner))),
     }
 }
+
+/// Applies a composable's namespace prefix to one of its field names, e.g.
+/// `prefixed_field_name("user", "count")` returns `"userCount"`. Used by the prefix mode of
+/// [composite_object](composite_object), [composite_subscription](composite_subscription) and
+/// [composite_interface](composite_interface) to expose a composable's fields under its
+/// namespace instead of their own names.
+#[doc(hidden)]
+#[must_use]
+pub fn prefixed_field_name(prefix: &str, field_name: &str) -> String {
+    let mut result = String::with_capacity(prefix.len() + field_name.len());
+    result.push_str(prefix);
+    let mut chars = field_name.chars();
+    if let Some(first) = chars.next() {
+        result.extend(first.to_uppercase());
+        result.extend(chars);
+    }
+    result
+}
+
+/// The inverse of [prefixed_field_name](prefixed_field_name): recovers a composable's own
+/// field name from an incoming, namespace-prefixed one, or returns `None` if `field_name`
+/// does not belong to `prefix`'s namespace. A bare byte-prefix match is not enough — `"user"` is
+/// also a byte-prefix of the unrelated field `"username"` — so the character right after the
+/// stripped prefix must be uppercase, i.e. the actual camelCase boundary
+/// [prefixed_field_name](prefixed_field_name) would have introduced.
+#[doc(hidden)]
+#[must_use]
+pub fn strip_field_prefix(prefix: &str, field_name: &str) -> Option<String> {
+    let rest = field_name.strip_prefix(prefix)?;
+    let mut chars = rest.chars();
+    let first = chars.next()?;
+    if !first.is_uppercase() {
+        return None;
+    }
+    let mut result = String::with_capacity(rest.len());
+    result.extend(first.to_lowercase());
+    result.extend(chars);
+    Some(result)
+}
+
+#[doc(hidden)]
+#[must_use]
+pub const fn str_eq(l: &str, r: &str) -> bool {
+    let l = l.as_bytes();
+    let r = r.as_bytes();
+    if l.len() != r.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < l.len() {
+        if l[i] != r[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// The length of the GraphQL field name [prefixed_field_name](prefixed_field_name) would
+/// produce for `(prefix, field)`, without actually allocating it.
+const fn expanded_len(prefix: Option<&str>, field: &str) -> usize {
+    match prefix {
+        Some(prefix) => prefix.len() + field.len(),
+        None => field.len(),
+    }
+}
+
+/// The byte at `index` of the GraphQL field name [prefixed_field_name](prefixed_field_name)
+/// would produce for `(prefix, field)`, without actually allocating it. Only exercised on ASCII
+/// identifiers (Rust method names), so an ASCII-only uppercase step stands in for
+/// [prefixed_field_name](prefixed_field_name)'s `char::to_uppercase`.
+const fn expanded_byte(prefix: Option<&str>, field: &str, index: usize) -> u8 {
+    let field = field.as_bytes();
+    match prefix {
+        Some(prefix) => {
+            let prefix = prefix.as_bytes();
+            if index < prefix.len() {
+                prefix[index]
+            } else if index == prefix.len() {
+                field[0].to_ascii_uppercase()
+            } else {
+                field[index - prefix.len()]
+            }
+        }
+        None => field[index],
+    }
+}
+
+/// Whether a `(prefix, field)` composable field and another `(prefix, field)` composable field
+/// would expose the same GraphQL field name once [prefixed_field_name](prefixed_field_name) is
+/// applied to each (a no-op for an unprefixed composable).
+const fn field_name_eq(
+    prefix_a: Option<&str>,
+    field_a: &str,
+    prefix_b: Option<&str>,
+    field_b: &str,
+) -> bool {
+    if prefix_a.is_none() && prefix_b.is_none() {
+        return str_eq(field_a, field_b);
+    }
+    let len_a = expanded_len(prefix_a, field_a);
+    let len_b = expanded_len(prefix_b, field_b);
+    if len_a != len_b {
+        return false;
+    }
+    let mut i = 0;
+    while i < len_a {
+        if expanded_byte(prefix_a, field_a, i) != expanded_byte(prefix_b, field_b, i) {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Checks that no two composables would expose the same GraphQL field name, once each
+/// composable's prefix (`None` for an unprefixed composable) is applied to its own field list.
+/// Used by [composite_object](composite_object), [composite_subscription](composite_subscription)
+/// and [composite_interface](composite_interface) to turn conflicting fields — including a
+/// prefixed field colliding with another composable's prefixed or unprefixed field — into a
+/// compile-time error.
+#[doc(hidden)]
+#[must_use]
+#[allow(clippy::many_single_char_names)]
+pub const fn no_duplicate_fields(composables: &[(Option<&str>, &[&str])]) -> bool {
+    let mut i = 0;
+    while i < composables.len() {
+        let (prefix_i, fields_i) = composables[i];
+        let mut j = 0;
+        while j < fields_i.len() {
+            let field = fields_i[j];
+
+            let mut k = j + 1;
+            while k < fields_i.len() {
+                if field_name_eq(prefix_i, field, prefix_i, fields_i[k]) {
+                    return false;
+                }
+                k += 1;
+            }
+
+            let mut m = i + 1;
+            while m < composables.len() {
+                let (prefix_m, fields_m) = composables[m];
+                let mut n = 0;
+                while n < fields_m.len() {
+                    if field_name_eq(prefix_i, field, prefix_m, fields_m[n]) {
+                        return false;
+                    }
+                    n += 1;
+                }
+                m += 1;
+            }
+
+            j += 1;
+        }
+        i += 1;
+    }
+    true
+}
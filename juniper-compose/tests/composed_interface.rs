@@ -0,0 +1,104 @@
+use juniper_compose::{composable_object, composite_interface, composite_object};
+
+struct Context;
+impl juniper::Context for Context {}
+
+#[derive(Default)]
+struct IdentifiableFields;
+
+#[composable_object]
+#[juniper::graphql_object(Context = Context)]
+impl IdentifiableFields {
+    fn id(&self) -> i32 {
+        111
+    }
+}
+
+composite_interface!(Node<Context = Context>(IdentifiableFields));
+
+#[derive(Default)]
+struct UserFields;
+
+#[composable_object]
+#[juniper::graphql_object(Context = Context)]
+impl UserFields {
+    fn name(&self) -> i32 {
+        222
+    }
+}
+
+composite_object!(User<Context = Context, Interfaces = [Node]>(IdentifiableFields, UserFields));
+
+#[derive(Default)]
+struct Query;
+
+// `User` only implements `GraphQLValue<DefaultScalarValue>`, so `Query` must pin the same
+// scalar instead of staying generic over it.
+#[juniper::graphql_object(Context = Context, Scalar = juniper::DefaultScalarValue)]
+impl Query {
+    fn user(&self) -> User {
+        User
+    }
+}
+
+#[test]
+fn object_implementing_a_composed_interface_exposes_its_shared_fields_and_is_registered_on_it() {
+    let schema = juniper::RootNode::new(
+        Query,
+        juniper::EmptyMutation::<Context>::new(),
+        juniper::EmptySubscription::<Context>::new(),
+    );
+
+    let (result, errors) = futures::executor::block_on(juniper::execute(
+        "query { user { id name } }",
+        None,
+        &schema,
+        &juniper::Variables::new(),
+        &Context,
+    ))
+    .unwrap();
+
+    assert!(errors.is_empty());
+    let user = result
+        .as_object_value()
+        .unwrap()
+        .get_field_value("user")
+        .unwrap()
+        .as_object_value()
+        .unwrap();
+    assert_eq!(user.get_field_value("id"), Some(&juniper::Value::scalar(111)));
+    assert_eq!(
+        user.get_field_value("name"),
+        Some(&juniper::Value::scalar(222))
+    );
+
+    let (result, errors) = futures::executor::block_on(juniper::execute(
+        r#"query { __type(name: "User") { interfaces { name } } }"#,
+        None,
+        &schema,
+        &juniper::Variables::new(),
+        &Context,
+    ))
+    .unwrap();
+
+    assert!(errors.is_empty());
+    let interfaces = result
+        .as_object_value()
+        .unwrap()
+        .get_field_value("__type")
+        .unwrap()
+        .as_object_value()
+        .unwrap()
+        .get_field_value("interfaces")
+        .unwrap()
+        .as_list_value()
+        .unwrap();
+    assert_eq!(interfaces.len(), 1);
+    assert_eq!(
+        interfaces[0]
+            .as_object_value()
+            .unwrap()
+            .get_field_value("name"),
+        Some(&juniper::Value::scalar("Node"))
+    );
+}
@@ -0,0 +1,59 @@
+use juniper_compose::{composable_object, composite_object};
+
+struct Context;
+impl juniper::Context for Context {}
+
+#[derive(Default)]
+struct UserQueries;
+
+#[composable_object]
+#[juniper::graphql_object(Context = Context)]
+impl UserQueries {
+    fn name(&self) -> i32 {
+        111
+    }
+}
+
+#[derive(Default)]
+struct Misc;
+
+#[composable_object]
+#[juniper::graphql_object(Context = Context)]
+impl Misc {
+    // Shares a byte-prefix ("user") with `UserQueries::name`'s prefixed name ("userName")
+    // without actually being namespaced under it; a naive prefix strip would misroute this
+    // field's queries to `UserQueries::name` instead.
+    fn username(&self) -> i32 {
+        222
+    }
+}
+
+composite_object!(Query<Context = Context>(user: UserQueries, Misc));
+
+#[test]
+fn prefixed_fields_do_not_misroute_on_a_shared_byte_prefix() {
+    let schema = juniper::RootNode::new(
+        Query,
+        juniper::EmptyMutation::<Context>::new(),
+        juniper::EmptySubscription::<Context>::new(),
+    );
+
+    let (result, errors) = futures::executor::block_on(juniper::execute(
+        "query { userName username }",
+        None,
+        &schema,
+        &juniper::Variables::new(),
+        &Context,
+    ))
+    .unwrap();
+
+    assert!(errors.is_empty());
+    assert_eq!(
+        result.as_object_value().unwrap().get_field_value("userName"),
+        Some(&juniper::Value::scalar(111))
+    );
+    assert_eq!(
+        result.as_object_value().unwrap().get_field_value("username"),
+        Some(&juniper::Value::scalar(222))
+    );
+}
@@ -0,0 +1,65 @@
+use futures::{Stream, StreamExt};
+use juniper_compose::{composable_subscription, composite_subscription};
+use std::pin::Pin;
+
+struct Context;
+impl juniper::Context for Context {}
+
+#[derive(Default)]
+struct Query;
+
+#[juniper::graphql_object(Context = Context)]
+impl Query {
+    fn noop(&self) -> i32 {
+        0
+    }
+}
+
+#[derive(Default)]
+struct UserSubscriptions;
+
+#[composable_subscription]
+#[juniper::graphql_subscription(Context = Context)]
+impl UserSubscriptions {
+    async fn counter(&self) -> Pin<Box<dyn Stream<Item = i32> + Send>> {
+        Box::pin(futures::stream::iter(vec![1, 2, 3]))
+    }
+}
+
+composite_subscription!(Subscription<Context = Context>(UserSubscriptions));
+
+#[test]
+fn composed_subscription_dispatches_to_the_underlying_stream() {
+    let schema = juniper::RootNode::new(
+        Query,
+        juniper::EmptyMutation::<Context>::new(),
+        Subscription,
+    );
+
+    let (value, errors) = futures::executor::block_on(juniper::resolve_into_stream(
+        "subscription { counter }",
+        None,
+        &schema,
+        &juniper::Variables::new(),
+        &Context,
+    ))
+    .unwrap();
+
+    assert!(errors.is_empty());
+
+    let mut fields = match value {
+        juniper::Value::Object(object) => object.into_iter().collect::<Vec<_>>(),
+        _ => panic!("expected an object value"),
+    };
+    assert_eq!(fields.len(), 1);
+    let (name, field_value) = fields.remove(0);
+    assert_eq!(name, "counter");
+
+    let mut stream = match field_value {
+        juniper::Value::Scalar(stream) => stream,
+        _ => panic!("expected a stream for `counter`"),
+    };
+
+    let first = futures::executor::block_on(stream.next());
+    assert_eq!(first, Some(Ok(juniper::Value::scalar(1))));
+}
@@ -0,0 +1,174 @@
+use juniper::ScalarValue;
+use juniper_compose::{composable_object, composite_object};
+use serde::{de, Deserialize, Deserializer, Serialize};
+use std::fmt;
+
+#[derive(juniper::GraphQLScalarValue, Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+enum MyScalarValue {
+    Int(i32),
+    Long(i64),
+    Float(f64),
+    String(String),
+    Boolean(bool),
+}
+
+impl ScalarValue for MyScalarValue {
+    fn as_int(&self) -> Option<i32> {
+        match *self {
+            Self::Int(i) => Some(i),
+            _ => None,
+        }
+    }
+
+    fn as_string(&self) -> Option<String> {
+        match self {
+            Self::String(s) => Some(s.clone()),
+            _ => None,
+        }
+    }
+
+    fn into_string(self) -> Option<String> {
+        match self {
+            Self::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(s) => Some(s.as_str()),
+            _ => None,
+        }
+    }
+
+    fn as_float(&self) -> Option<f64> {
+        match self {
+            Self::Int(i) => Some(f64::from(*i)),
+            Self::Float(f) => Some(*f),
+            _ => None,
+        }
+    }
+
+    fn as_boolean(&self) -> Option<bool> {
+        match self {
+            Self::Boolean(b) => Some(*b),
+            _ => None,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for MyScalarValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct MyScalarValueVisitor;
+
+        impl<'de> de::Visitor<'de> for MyScalarValueVisitor {
+            type Value = MyScalarValue;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("a valid input value")
+            }
+
+            fn visit_bool<E>(self, value: bool) -> Result<Self::Value, E> {
+                Ok(MyScalarValue::Boolean(value))
+            }
+
+            fn visit_i32<E>(self, value: i32) -> Result<Self::Value, E> {
+                Ok(MyScalarValue::Int(value))
+            }
+
+            fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                if value <= i64::from(i32::MAX) {
+                    self.visit_i32(value.try_into().unwrap())
+                } else {
+                    Ok(MyScalarValue::Long(value))
+                }
+            }
+
+            fn visit_u32<E>(self, value: u32) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                if value <= i32::MAX as u32 {
+                    self.visit_i32(value.try_into().unwrap())
+                } else {
+                    self.visit_u64(value.into())
+                }
+            }
+
+            fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                if value <= i64::MAX as u64 {
+                    self.visit_i64(value.try_into().unwrap())
+                } else {
+                    Ok(MyScalarValue::Float(value as f64))
+                }
+            }
+
+            fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E> {
+                Ok(MyScalarValue::Float(value))
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                self.visit_string(value.into())
+            }
+
+            fn visit_string<E>(self, value: String) -> Result<Self::Value, E> {
+                Ok(MyScalarValue::String(value))
+            }
+        }
+
+        deserializer.deserialize_any(MyScalarValueVisitor)
+    }
+}
+
+struct Context;
+impl juniper::Context for Context {}
+
+#[derive(Default)]
+struct UserQueries;
+
+#[composable_object]
+#[juniper::graphql_object(Context = Context, Scalar = MyScalarValue)]
+impl UserQueries {
+    fn name(&self) -> i32 {
+        111
+    }
+}
+
+composite_object!(Query<Context = Context, Scalar = MyScalarValue>(UserQueries));
+
+#[test]
+fn composes_over_a_custom_scalar_value() {
+    let schema = juniper::RootNode::new_with_scalar_value(
+        Query,
+        juniper::EmptyMutation::<Context>::new(),
+        juniper::EmptySubscription::<Context>::new(),
+    );
+
+    let (result, errors) = futures::executor::block_on(juniper::execute(
+        "query { name }",
+        None,
+        &schema,
+        &juniper::Variables::new(),
+        &Context,
+    ))
+    .unwrap();
+
+    assert!(errors.is_empty());
+    assert_eq!(
+        result.as_object_value().unwrap().get_field_value("name"),
+        Some(&juniper::Value::scalar(111))
+    );
+}
@@ -0,0 +1,62 @@
+use juniper_compose::{composable_object, composite_object};
+
+struct Context;
+impl juniper::Context for Context {}
+
+#[derive(Default)]
+struct UserQueries;
+
+#[composable_object]
+#[juniper::graphql_object(Context = Context)]
+impl UserQueries {
+    #[graphql(name = "userCount")]
+    fn count(&self) -> i32 {
+        111
+    }
+
+    #[graphql(skip)]
+    fn helper(&self) -> i32 {
+        222
+    }
+
+    // A juniper field attribute whose argument is itself a parenthesized group (e.g.
+    // `arguments(id(description = "..."))`) must round-trip untouched instead of breaking the
+    // parser.
+    #[graphql(arguments(id(description = "the user id")))]
+    fn by_id(&self, id: i32) -> i32 {
+        id
+    }
+}
+
+composite_object!(Query<Context = Context>(UserQueries));
+
+#[test]
+fn renamed_skipped_and_nested_attribute_fields_resolve_correctly() {
+    let schema = juniper::RootNode::new(
+        Query,
+        juniper::EmptyMutation::<Context>::new(),
+        juniper::EmptySubscription::<Context>::new(),
+    );
+
+    let (result, errors) = futures::executor::block_on(juniper::execute(
+        "query { userCount byId(id: 7) }",
+        None,
+        &schema,
+        &juniper::Variables::new(),
+        &Context,
+    ))
+    .unwrap();
+
+    assert!(errors.is_empty());
+    assert_eq!(
+        result
+            .as_object_value()
+            .unwrap()
+            .get_field_value("userCount"),
+        Some(&juniper::Value::scalar(111))
+    );
+    assert_eq!(
+        result.as_object_value().unwrap().get_field_value("byId"),
+        Some(&juniper::Value::scalar(7))
+    );
+}
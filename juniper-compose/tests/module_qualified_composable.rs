@@ -0,0 +1,45 @@
+use juniper_compose::{composable_object, composite_object};
+
+pub struct Context;
+impl juniper::Context for Context {}
+
+pub mod inner {
+    use juniper_compose::composable_object;
+
+    #[derive(Default)]
+    pub struct UserQueries;
+
+    #[composable_object]
+    #[juniper::graphql_object(Context = super::Context)]
+    impl UserQueries {
+        fn name(&self) -> i32 {
+            111
+        }
+    }
+}
+
+composite_object!(Query<Context = Context>(inner::UserQueries));
+
+#[test]
+fn module_qualified_composable_path_parses_and_resolves() {
+    let schema = juniper::RootNode::new(
+        Query,
+        juniper::EmptyMutation::<Context>::new(),
+        juniper::EmptySubscription::<Context>::new(),
+    );
+
+    let (result, errors) = futures::executor::block_on(juniper::execute(
+        "query { name }",
+        None,
+        &schema,
+        &juniper::Variables::new(),
+        &Context,
+    ))
+    .unwrap();
+
+    assert!(errors.is_empty());
+    assert_eq!(
+        result.as_object_value().unwrap().get_field_value("name"),
+        Some(&juniper::Value::scalar(111))
+    );
+}